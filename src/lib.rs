@@ -56,6 +56,7 @@
 //! ```
 
 #![deny(missing_docs)]
+use std::cmp::Ordering;
 use std::iter::FromIterator;
 use std::string::ToString;
 
@@ -125,6 +126,270 @@ pub fn custom_group(num: &str,
     grouped_string
 }
 
+/// Errors that can occur when un-grouping a formatted numeric string with [`parse_grouped`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UngroupError {
+    /// The grouping delimiter is the same character as the decimal mark, so the input cannot be
+    /// unambiguously un-grouped.
+    AmbiguousDelimiter,
+    /// A grouping delimiter did not fall on a valid group boundary.
+    MisplacedDelimiter,
+}
+
+/// Checks that the grouping delimiters in `integral_digits` fall on the group boundaries implied
+/// by `first_group_size` and `group_size`, scanning from the right (least-significant digit)
+/// outward, the same direction `groupify_integer` groups in. Any `char` in `delimiters` is
+/// treated as a group boundary marker.
+///
+/// In addition to the boundary position, every delimiter must be preceded by a nonzero run of
+/// digits since the last delimiter (or since the start of the scan), and the string may not end
+/// with a delimiter (i.e. begin with one, since the scan runs right-to-left). This rejects things
+/// `groupify_integer` would never produce, such as doubled delimiters (`"123,,456"`) or a leading
+/// delimiter (`",123,456"`).
+fn validate_integer_groups(integral_digits: &str,
+                           delimiters: &[char],
+                           first_group_size: usize,
+                           group_size: usize)
+                           -> Result<(), UngroupError> {
+    let mut digits_since_right = 0;
+    let mut digits_since_last_delimiter = 0;
+    let mut last_char_was_delimiter = false;
+
+    for c in integral_digits.chars().rev() {
+        if delimiters.contains(&c) {
+            let on_boundary = if digits_since_right <= first_group_size {
+                digits_since_right == first_group_size
+            } else {
+                let digits_past_first_group = digits_since_right - first_group_size;
+                digits_past_first_group.is_multiple_of(group_size)
+            };
+            if digits_since_last_delimiter == 0 || !on_boundary {
+                return Err(UngroupError::MisplacedDelimiter);
+            }
+            digits_since_last_delimiter = 0;
+            last_char_was_delimiter = true;
+        } else {
+            digits_since_right += 1;
+            digits_since_last_delimiter += 1;
+            last_char_was_delimiter = false;
+        }
+    }
+
+    if last_char_was_delimiter {
+        return Err(UngroupError::MisplacedDelimiter);
+    }
+
+    Ok(())
+}
+
+/// Parses a grouped number back into a clean numeric string, the inverse of [`custom_group`].
+///
+/// Strips every occurrence of any `char` in `grouping_delimiters` and normalizes `decimal_mark`
+/// to `.`, so the result can be parsed directly with `str::parse`, e.g. into `f64` or `i64`.
+///
+/// `grouped` is a grouped numeric string such as `"123,456,789.012 34"`.
+///
+/// `decimal_mark` is the `char` used to delimit the integer and fractional portions of the number.
+///
+/// `grouping_delimiters` is the set of delimiters to strip from between groups.
+///
+/// `first_group_size` and `group_size` describe the expected group boundaries and are only used
+/// when `validate_groups` is `true`.
+///
+/// `validate_groups` determines whether the delimiters in the integer part must fall on the group
+/// boundaries implied by `first_group_size`/`group_size`; pass `false` to skip this check and
+/// simply strip every delimiter.
+///
+/// # Errors
+///
+/// Returns `Err(UngroupError::AmbiguousDelimiter)` if `grouping_delimiters` contains `decimal_mark`.
+///
+/// Returns `Err(UngroupError::MisplacedDelimiter)` if `validate_groups` is `true` and a delimiter
+/// in the integer part does not fall on a group boundary.
+///
+/// # Examples
+///
+/// ```
+/// # use digit_group::parse_grouped;
+///
+/// let n = parse_grouped("123,456,789.012 34", '.', &[',', ' '], 3, 3, true).unwrap();
+/// assert_eq!(n, "123456789.01234");
+/// assert_eq!(n.parse::<f64>().unwrap(), 123456789.01234_f64);
+/// ```
+pub fn parse_grouped(grouped: &str,
+                     decimal_mark: char,
+                     grouping_delimiters: &[char],
+                     first_group_size: usize,
+                     group_size: usize,
+                     validate_groups: bool)
+                     -> Result<String, UngroupError> {
+    if grouping_delimiters.contains(&decimal_mark) {
+        return Err(UngroupError::AmbiguousDelimiter);
+    }
+
+    let mut parts = grouped.splitn(2, decimal_mark);
+    let integer_part = parts.next().unwrap_or("");
+    let fractional_part = parts.next();
+
+    let (sign, unsigned_integer_part) = match integer_part.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", integer_part),
+    };
+
+    if validate_groups {
+        validate_integer_groups(unsigned_integer_part,
+                                grouping_delimiters,
+                                first_group_size,
+                                group_size)?;
+    }
+
+    let mut result = String::from(sign);
+    result.push_str(&strip_chars(unsigned_integer_part, grouping_delimiters));
+
+    if let Some(fractional_part) = fractional_part {
+        result.push('.');
+        result.push_str(&strip_chars(fractional_part, grouping_delimiters));
+    }
+
+    Ok(result)
+}
+
+/// Returns a copy of `s` with every `char` in `chars_to_strip` removed.
+fn strip_chars(s: &str, chars_to_strip: &[char]) -> String {
+    s.chars().filter(|c| !chars_to_strip.contains(c)).collect()
+}
+
+/// Counts the digits in the integer part of a pre-formatted number, ignoring a leading `-`.
+fn integer_digit_count(num: &str) -> usize {
+    let integer_part = num.split('.').next().unwrap_or("");
+    integer_part.trim_start_matches('-').len()
+}
+
+/// Groups `num` according to the given parameters, unless its integer part has fewer than
+/// `min_digits_to_group` digits, in which case the decimal mark is still normalized but no
+/// grouping delimiters are inserted. This mirrors the reasoning behind Clippy's
+/// `unreadable_literal`/`large_digit_groups` lints, which only fire on sufficiently long
+/// literals because short numbers don't benefit from separators.
+#[allow(clippy::too_many_arguments)]
+fn custom_group_with_threshold(num: &str,
+                               decimal_mark: char,
+                               grouping_delimiter: char,
+                               first_group_size: usize,
+                               group_size: usize,
+                               group_fractional_part: bool,
+                               min_digits_to_group: usize)
+                               -> String {
+    if integer_digit_count(num) < min_digits_to_group {
+        custom_group(num, decimal_mark, grouping_delimiter, usize::MAX, usize::MAX, group_fractional_part)
+    } else {
+        custom_group(num, decimal_mark, grouping_delimiter, first_group_size, group_size, group_fractional_part)
+    }
+}
+
+/// Selects how [`FormatGroup::format_rounded`] breaks ties when the discarded remainder is
+/// exactly one half.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Ties round away from zero, e.g. `2.5` rounds to `3` and `-2.5` rounds to `-3`. This is the
+    /// mode `format!`'s precision formatting does not provide.
+    HalfAwayFromZero,
+    /// Ties round to the nearest even digit, e.g. `2.5` rounds to `2` and `3.5` rounds to `4`.
+    HalfToEven,
+}
+
+/// Prepends zeros to `digits` until `digits.len() == len`.
+fn left_pad_zeros(mut digits: String, len: usize) -> String {
+    while digits.len() < len {
+        digits = format!("0{}", digits);
+    }
+    digits
+}
+
+/// Adds one to the non-negative integer represented by `digits`, as a string operation, handling
+/// carries (e.g. `"099"` becomes `"100"`).
+fn increment_digit_string(digits: &str) -> String {
+    let mut bytes = digits.bytes().collect::<Vec<_>>();
+    let mut i = bytes.len();
+    loop {
+        if i == 0 {
+            bytes.insert(0, b'1');
+            break;
+        }
+        i -= 1;
+        if bytes[i] == b'9' {
+            bytes[i] = b'0';
+        } else {
+            bytes[i] += 1;
+            break;
+        }
+    }
+    String::from_utf8(bytes).expect("digits are always ASCII")
+}
+
+/// Rounds a pre-formatted decimal string to `precision` fractional digits using `mode`,
+/// performing the scaling/rounding on the string itself rather than on a raw float. This avoids
+/// binary floating-point artifacts (e.g. `2.675` rounding to `2.67` because the nearest `f64` to
+/// `2.675` is actually slightly below it) since `ToString` on a float yields the shortest decimal
+/// representation that round-trips to the same value.
+///
+/// To round to `precision` digits: scale by `10^precision`, take the integer part, and if the
+/// discarded remainder is exactly one half, increment the magnitude away from zero or to the
+/// nearest even digit (depending on `mode`); otherwise round to nearest. The decimal point is
+/// then reinserted `precision` digits from the right, left-padding with zeros as needed.
+fn round_decimal_string(num: &str, precision: usize, mode: RoundingMode) -> String {
+    let (sign, unsigned) = match num.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", num),
+    };
+
+    let mut parts = unsigned.splitn(2, '.');
+    let int_part = parts.next().unwrap_or("");
+    let frac_part = parts.next().unwrap_or("");
+
+    let rounded_digits = if frac_part.len() <= precision {
+        format!("{}{}{}", int_part, frac_part, "0".repeat(precision - frac_part.len()))
+    } else {
+        let combined = format!("{}{}", int_part, &frac_part[..precision]);
+
+        let digit_to_round = frac_part.as_bytes()[precision];
+        let rest_all_zero = frac_part[precision + 1..].bytes().all(|b| b == b'0');
+        let discarded_remainder = match digit_to_round {
+            b if b < b'5' => Ordering::Less,
+            b if b > b'5' => Ordering::Greater,
+            _ if rest_all_zero => Ordering::Equal,
+            _ => Ordering::Greater,
+        };
+
+        let last_kept_digit = combined.as_bytes()[combined.len() - 1] - b'0';
+        let round_up = match discarded_remainder {
+            Ordering::Less => false,
+            Ordering::Greater => true,
+            Ordering::Equal => match mode {
+                RoundingMode::HalfAwayFromZero => true,
+                RoundingMode::HalfToEven => last_kept_digit % 2 == 1,
+            },
+        };
+
+        if round_up {
+            increment_digit_string(&combined)
+        } else {
+            combined
+        }
+    };
+
+    let rounded_digits = left_pad_zeros(rounded_digits, precision + 1);
+    let split_at = rounded_digits.len() - precision;
+    let (int_digits, frac_digits) = rounded_digits.split_at(split_at);
+
+    let mut result = String::from(sign);
+    result.push_str(int_digits);
+    if precision > 0 {
+        result.push('.');
+        result.push_str(frac_digits);
+    }
+    result
+}
+
 /// Various formatters provided for integer grouping.
 pub trait FormatGroup {
     /// Formats the number according to ISO 80000-1, using a custom `decimal_mark`.
@@ -151,6 +416,21 @@ pub trait FormatGroup {
     /// ```
     fn format_commas(&self) -> String;
 
+    /// Formats the integral value into groups of three, separated by commas, unless it has fewer
+    /// than `min_digits_to_group` integer digits, in which case it is returned unchanged. This
+    /// avoids over-separating small values in tables, matching the readable-literal convention
+    /// behind Clippy's `unreadable_literal` lint.
+    ///
+    /// #Example
+    ///
+    /// ```
+    /// use digit_group::FormatGroup;
+    ///
+    /// assert_eq!(999.format_commas_min(4), "999");
+    /// assert_eq!(1000.format_commas_min(4), "1,000");
+    /// ```
+    fn format_commas_min(&self, min_digits_to_group: usize) -> String;
+
     /// Formats the number based on supplied parameters.
     ///
     /// `decimal_mark` is the `char` used to delimit the integer and fractional portions of the
@@ -180,6 +460,37 @@ pub trait FormatGroup {
                      group_size: usize,
                      group_fractional_part: bool)
                      -> String;
+
+    /// Rounds the number to `precision` fractional digits using `mode`, then formats it based on
+    /// the supplied grouping parameters. This spares callers from having to pre-round via
+    /// `format!("{:.*}", precision, x)`, which only ever rounds half-to-even and never
+    /// half-away-from-zero.
+    ///
+    /// `precision` is the number of fractional digits to round to.
+    ///
+    /// `mode` selects how exact ties are broken.
+    ///
+    /// See [`FormatGroup::format_custom`] for `decimal_mark`, `grouping_delimiter`,
+    /// `first_group_size`, `group_size`, and `group_fractional_part`.
+    ///
+    /// #Example
+    ///
+    /// ```
+    /// use digit_group::{FormatGroup, RoundingMode};
+    ///
+    /// let x: f64 = 2.675;
+    /// assert_eq!(x.format_rounded(2, RoundingMode::HalfAwayFromZero, '.', ',', 3, 3, false), "2.68")
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    fn format_rounded(&self,
+                      precision: usize,
+                      mode: RoundingMode,
+                      decimal_mark: char,
+                      grouping_delimiter: char,
+                      first_group_size: usize,
+                      group_size: usize,
+                      group_fractional_part: bool)
+                      -> String;
 }
 
 /// Convenience for `groupify_integer`.
@@ -298,6 +609,11 @@ macro_rules! impl_FormatGroup {
                 self.format_custom('.', ',', 3, 3, false)
             }
 
+            fn format_commas_min(&self, min_digits_to_group: usize) -> String {
+                let stringy_number = self.to_string();
+                custom_group_with_threshold(&stringy_number, '.', ',', 3, 3, false, min_digits_to_group)
+            }
+
             fn format_custom(&self,
                     decimal_mark: char,
                     grouping_delimiter: char,
@@ -306,10 +622,29 @@ macro_rules! impl_FormatGroup {
                     group_fractional_part: bool)
                     -> String {
                 let stringy_number = self.to_string();
-                custom_group(&stringy_number, 
-                             decimal_mark, 
-                             grouping_delimiter, 
-                             first_group_size, 
+                custom_group(&stringy_number,
+                             decimal_mark,
+                             grouping_delimiter,
+                             first_group_size,
+                             group_size,
+                             group_fractional_part)
+            }
+
+            fn format_rounded(&self,
+                    precision: usize,
+                    mode: RoundingMode,
+                    decimal_mark: char,
+                    grouping_delimiter: char,
+                    first_group_size: usize,
+                    group_size: usize,
+                    group_fractional_part: bool)
+                    -> String {
+                let stringy_number = self.to_string();
+                let rounded = round_decimal_string(&stringy_number, precision, mode);
+                custom_group(&rounded,
+                             decimal_mark,
+                             grouping_delimiter,
+                             first_group_size,
                              group_size,
                              group_fractional_part)
             }
@@ -333,9 +668,336 @@ impl_FormatGroup!(usize);
 impl_FormatGroup!(f32);
 impl_FormatGroup!(f64);
 
+/// Returns the conventional digit group size for a given `radix`, following the convention used
+/// by Clippy's `unreadable_literal`/`large_digit_groups` lints: binary and hexadecimal group in
+/// runs of 4 (`0xF0F0_F0F0`, `1111_0000`), everything else groups in runs of 3.
+fn default_group_size_for_radix(radix: u32) -> usize {
+    match radix {
+        2 | 16 => 4,
+        _ => 3,
+    }
+}
+
+/// Groups a pre-formatted, unsigned radix string (as produced by the `{:b}`, `{:o}`, or `{:X}`
+/// formatters) using the supplied group sizes.
+///
+/// This is the radix-aware counterpart to [`custom_group`]; unlike `custom_group` it has no
+/// concept of a decimal mark or fractional part, since the radix formatting in this crate is
+/// integer-only.
+///
+/// `num` is a pre-formatted `&str` of an integer value in the target radix.
+///
+/// `grouping_delimiter` is the delimiter to use between groups.
+///
+/// `first_group_size` is the number of digits of the initial group.
+///
+/// `group_size` is the number of digits of subsequent groups.
+///
+/// # Examples
+///
+/// ```
+/// # use digit_group::custom_group_radix;
+///
+/// let grouped = custom_group_radix("F0F0F0F0", '_', 4, 4);
+/// assert_eq!(grouped, "F0F0_F0F0");
+/// ```
+pub fn custom_group_radix(num: &str,
+                          grouping_delimiter: char,
+                          first_group_size: usize,
+                          group_size: usize)
+                          -> String {
+    groupify_integer(num.chars(),
+                     grouping_delimiter,
+                     first_group_size,
+                     group_size,
+                     GroupDirection::RightToLeft)
+}
+
+/// Provides base-2, base-8, and base-16 grouped formatting for integer types.
+///
+/// Binary and hexadecimal values are grouped in runs of 4 digits by default (matching the
+/// convention used by Clippy's literal lints), while octal falls back to runs of 3, consistent
+/// with [`FormatGroup::format_commas`].
+///
+/// For signed types, negative values are rendered the same way Rust's own `{:b}`/`{:o}`/`{:x}`
+/// formatters render them: as the full-width two's-complement bit pattern, with no `-` sign. For
+/// example, `(-1i32).format_radix(16, '_')` is `"FFFF_FFFF"`, not `"-1"`. This differs from
+/// [`custom_group`], which does special-case a leading `-`.
+pub trait FormatRadix {
+    /// Formats the value in the given `radix` (2, 8, or 16), applying the default digit grouping
+    /// for that radix.
+    ///
+    /// Negative values are rendered as their two's-complement bit pattern; see the trait-level
+    /// documentation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not 2, 8, or 16.
+    ///
+    /// #Example
+    ///
+    /// ```
+    /// use digit_group::FormatRadix;
+    ///
+    /// let x: u32 = 0xF0F0_F0F0;
+    /// assert_eq!(x.format_radix(16, '_'), "F0F0_F0F0");
+    /// ```
+    fn format_radix(&self, radix: u32, grouping_delimiter: char) -> String;
+
+    /// Formats the value in the given `radix`, overriding the default group sizes.
+    ///
+    /// `first_group_size` is the number of digits of the initial group.
+    ///
+    /// `group_size` is the number of digits of subsequent groups.
+    ///
+    /// Negative values are rendered as their two's-complement bit pattern; see the trait-level
+    /// documentation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not 2, 8, or 16.
+    ///
+    /// #Example
+    ///
+    /// ```
+    /// use digit_group::FormatRadix;
+    ///
+    /// let x: u32 = 0b1111_0000;
+    /// assert_eq!(x.format_radix_custom(2, ' ', 4, 4), "1111 0000");
+    /// ```
+    fn format_radix_custom(&self,
+                           radix: u32,
+                           grouping_delimiter: char,
+                           first_group_size: usize,
+                           group_size: usize)
+                           -> String;
+}
+
+macro_rules! impl_FormatRadix {
+    ($t:ty) => (
+
+        impl FormatRadix for $t {
+            fn format_radix(&self, radix: u32, grouping_delimiter: char) -> String {
+                let group_size = default_group_size_for_radix(radix);
+                self.format_radix_custom(radix, grouping_delimiter, group_size, group_size)
+            }
+
+            fn format_radix_custom(&self,
+                    radix: u32,
+                    grouping_delimiter: char,
+                    first_group_size: usize,
+                    group_size: usize)
+                    -> String {
+                let stringy_number = match radix {
+                    2 => format!("{:b}", self),
+                    8 => format!("{:o}", self),
+                    16 => format!("{:X}", self),
+                    _ => panic!("format_radix: unsupported radix {} (expected 2, 8, or 16)", radix),
+                };
+                custom_group_radix(&stringy_number,
+                                   grouping_delimiter,
+                                   first_group_size,
+                                   group_size)
+            }
+        }
+
+    )
+}
+
+impl_FormatRadix!(i8);
+impl_FormatRadix!(i16);
+impl_FormatRadix!(i32);
+impl_FormatRadix!(i64);
+impl_FormatRadix!(isize);
+
+impl_FormatRadix!(u8);
+impl_FormatRadix!(u16);
+impl_FormatRadix!(u32);
+impl_FormatRadix!(u64);
+impl_FormatRadix!(usize);
+
+/// A reusable, pre-configured grouping formatter.
+///
+/// `GroupFormatter` stores the decimal mark, grouping delimiter, group sizes, and
+/// `group_fractional_part` flag once, so the same configuration can be applied to many numbers
+/// without repeating all of `format_custom`'s arguments at every call site. This mirrors the
+/// `define-decimal-formatter` pattern from the Common Lisp `decimals` package, where a named
+/// formatter is predefined once and reused.
+///
+/// # Examples
+///
+/// ```
+/// use digit_group::GroupFormatter;
+///
+/// let fmt = GroupFormatter::commas();
+/// assert_eq!(fmt.format(123456789), "123,456,789");
+/// ```
+///
+/// Presets can still be customized via the chainable `with_*` methods:
+///
+/// ```
+/// use digit_group::GroupFormatter;
+///
+/// let fmt = GroupFormatter::si().with_decimal_mark(',');
+/// assert_eq!(fmt.format(123456789.01234_f64), "123 456 789,012 34");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GroupFormatter {
+    decimal_mark: char,
+    grouping_delimiter: char,
+    first_group_size: usize,
+    group_size: usize,
+    group_fractional_part: bool,
+    min_digits_to_group: usize,
+}
+
+impl GroupFormatter {
+    /// Creates a new `GroupFormatter` using the same defaults as [`FormatGroup::format_commas`]:
+    /// a `.` decimal mark, `,` grouping delimiter, groups of 3, an ungrouped fractional part, and
+    /// no minimum digit threshold.
+    pub fn new() -> GroupFormatter {
+        GroupFormatter {
+            decimal_mark: '.',
+            grouping_delimiter: ',',
+            first_group_size: 3,
+            group_size: 3,
+            group_fractional_part: false,
+            min_digits_to_group: 0,
+        }
+    }
+
+    /// Creates a `GroupFormatter` preconfigured like [`FormatGroup::format_si`]: groups of 3
+    /// separated by a space, with the fractional part grouped as well.
+    pub fn si() -> GroupFormatter {
+        GroupFormatter {
+            decimal_mark: '.',
+            grouping_delimiter: ' ',
+            first_group_size: 3,
+            group_size: 3,
+            group_fractional_part: true,
+            min_digits_to_group: 0,
+        }
+    }
+
+    /// Creates a `GroupFormatter` preconfigured like [`FormatGroup::format_commas`]: groups of 3
+    /// separated by commas, with the fractional part left ungrouped.
+    pub fn commas() -> GroupFormatter {
+        GroupFormatter::new()
+    }
+
+    /// Creates a `GroupFormatter` preconfigured for the Indian numbering system: an initial group
+    /// of 3 digits followed by groups of 2, separated by commas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use digit_group::GroupFormatter;
+    ///
+    /// let fmt = GroupFormatter::indian();
+    /// assert_eq!(fmt.format(1234567.89_f64), "12,34,567.89");
+    /// ```
+    pub fn indian() -> GroupFormatter {
+        GroupFormatter {
+            decimal_mark: '.',
+            grouping_delimiter: ',',
+            first_group_size: 3,
+            group_size: 2,
+            group_fractional_part: false,
+            min_digits_to_group: 0,
+        }
+    }
+
+    /// Sets the `char` used to delimit the integer and fractional portions of the number.
+    pub fn with_decimal_mark(mut self, decimal_mark: char) -> GroupFormatter {
+        self.decimal_mark = decimal_mark;
+        self
+    }
+
+    /// Sets the delimiter to use between groups.
+    pub fn with_delimiter(mut self, grouping_delimiter: char) -> GroupFormatter {
+        self.grouping_delimiter = grouping_delimiter;
+        self
+    }
+
+    /// Sets the initial and subsequent group sizes.
+    pub fn with_group_sizes(mut self, first_group_size: usize, group_size: usize) -> GroupFormatter {
+        self.first_group_size = first_group_size;
+        self.group_size = group_size;
+        self
+    }
+
+    /// Sets whether grouping rules are also applied to the fractional part of the number.
+    pub fn with_group_fractional_part(mut self, group_fractional_part: bool) -> GroupFormatter {
+        self.group_fractional_part = group_fractional_part;
+        self
+    }
+
+    /// Sets the minimum number of integer digits a value must have before grouping is applied;
+    /// values with fewer integer digits are returned ungrouped. Pass `0` (the default) to always
+    /// group.
+    pub fn with_min_digits_to_group(mut self, min_digits_to_group: usize) -> GroupFormatter {
+        self.min_digits_to_group = min_digits_to_group;
+        self
+    }
+
+    /// Formats `n` using this formatter's stored configuration.
+    ///
+    /// `n` may be any numeric type that implements `ToString`.
+    pub fn format<T: ToString>(&self, n: T) -> String {
+        custom_group_with_threshold(&n.to_string(),
+                                    self.decimal_mark,
+                                    self.grouping_delimiter,
+                                    self.first_group_size,
+                                    self.group_size,
+                                    self.group_fractional_part,
+                                    self.min_digits_to_group)
+    }
+
+    /// Rounds `n` to `precision` fractional digits using `mode`, then formats it using this
+    /// formatter's stored configuration.
+    ///
+    /// `n` may be any numeric type that implements `ToString`.
+    pub fn format_rounded<T: ToString>(&self, n: T, precision: usize, mode: RoundingMode) -> String {
+        let rounded = round_decimal_string(&n.to_string(), precision, mode);
+        custom_group_with_threshold(&rounded,
+                                    self.decimal_mark,
+                                    self.grouping_delimiter,
+                                    self.first_group_size,
+                                    self.group_size,
+                                    self.group_fractional_part,
+                                    self.min_digits_to_group)
+    }
+}
+
+impl Default for GroupFormatter {
+    fn default() -> GroupFormatter {
+        GroupFormatter::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{FormatGroup, custom_group};
+    use super::{FormatGroup, FormatRadix, GroupFormatter, RoundingMode, UngroupError, custom_group,
+               parse_grouped};
+
+    #[test]
+    fn format_commas_min_below_threshold() {
+        let x: i32 = 999;
+        assert_eq!(x.format_commas_min(4), "999");
+    }
+
+    #[test]
+    fn format_commas_min_at_threshold() {
+        let x: i32 = 1000;
+        assert_eq!(x.format_commas_min(4), "1,000");
+    }
+
+    #[test]
+    fn group_formatter_min_digits_to_group() {
+        let fmt = GroupFormatter::commas().with_min_digits_to_group(4);
+        assert_eq!(fmt.format(999), "999");
+        assert_eq!(fmt.format(1000), "1,000");
+    }
 
     #[test]
     fn u64_si() {
@@ -401,4 +1063,168 @@ mod tests {
         assert_eq!(s, "12,34,567.89");
     }
 
+    #[test]
+    fn u32_hex() {
+        let x: u32 = 0xF0F0_F0F0;
+        let s = x.format_radix(16, '_');
+        assert_eq!(s, "F0F0_F0F0");
+    }
+
+    #[test]
+    fn u32_binary() {
+        let x: u32 = 0b1111_0000_1111_0000;
+        let s = x.format_radix(2, '_');
+        assert_eq!(s, "1111_0000_1111_0000");
+    }
+
+    #[test]
+    fn u32_octal() {
+        let x: u32 = 0o17_777;
+        let s = x.format_radix(8, '_');
+        assert_eq!(s, "17_777");
+    }
+
+    #[test]
+    fn u32_radix_custom_group_sizes() {
+        let x: u32 = 0b1111_0000;
+        let s = x.format_radix_custom(2, ' ', 4, 4);
+        assert_eq!(s, "1111 0000");
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsupported_radix_panics() {
+        let x: u32 = 42;
+        x.format_radix(10, ',');
+    }
+
+    #[test]
+    fn negative_signed_radix_is_twos_complement() {
+        let x: i32 = -1;
+        let s = x.format_radix(16, '_');
+        assert_eq!(s, "FFFF_FFFF");
+    }
+
+    #[test]
+    fn group_formatter_commas() {
+        let fmt = GroupFormatter::commas();
+        assert_eq!(fmt.format(123456789), "123,456,789");
+    }
+
+    #[test]
+    fn group_formatter_si() {
+        let fmt = GroupFormatter::si();
+        let x: f64 = 123456789.1234567;
+        assert_eq!(fmt.format(x), "123 456 789.123 456 7");
+    }
+
+    #[test]
+    fn group_formatter_indian() {
+        let fmt = GroupFormatter::indian();
+        let x: f64 = 1234567.89;
+        assert_eq!(fmt.format(x), "12,34,567.89");
+    }
+
+    #[test]
+    fn group_formatter_chained_setters() {
+        let fmt = GroupFormatter::new()
+            .with_decimal_mark('#')
+            .with_delimiter(':')
+            .with_group_sizes(4, 2)
+            .with_group_fractional_part(false);
+        let x: f64 = 123456789.01;
+        assert_eq!(fmt.format(x), "1:23:45:6789#01");
+    }
+
+    #[test]
+    fn parse_grouped_si_mixed_delimiters() {
+        let n = parse_grouped("123,456,789.012 34", '.', &[',', ' '], 3, 3, true).unwrap();
+        assert_eq!(n, "123456789.01234");
+        assert_eq!(n.parse::<f64>().unwrap(), 123456789.01234_f64);
+    }
+
+    #[test]
+    fn parse_grouped_commas() {
+        let n = parse_grouped("123,456,789", '.', &[','], 3, 3, true).unwrap();
+        assert_eq!(n, "123456789");
+    }
+
+    #[test]
+    fn parse_grouped_negative() {
+        let n = parse_grouped("-1,234,567", '.', &[','], 3, 3, true).unwrap();
+        assert_eq!(n, "-1234567");
+        assert_eq!(n.parse::<i64>().unwrap(), -1234567);
+    }
+
+    #[test]
+    fn parse_grouped_india() {
+        let n = parse_grouped("12,34,567.89", '.', &[','], 3, 2, true).unwrap();
+        assert_eq!(n, "1234567.89");
+    }
+
+    #[test]
+    fn parse_grouped_ambiguous_delimiter() {
+        let err = parse_grouped("123,456", ',', &[','], 3, 3, true).unwrap_err();
+        assert_eq!(err, UngroupError::AmbiguousDelimiter);
+    }
+
+    #[test]
+    fn parse_grouped_misplaced_delimiter() {
+        let err = parse_grouped("12,3456", '.', &[','], 3, 3, true).unwrap_err();
+        assert_eq!(err, UngroupError::MisplacedDelimiter);
+    }
+
+    #[test]
+    fn parse_grouped_doubled_delimiter() {
+        let err = parse_grouped("123,,456,789", '.', &[','], 3, 3, true).unwrap_err();
+        assert_eq!(err, UngroupError::MisplacedDelimiter);
+    }
+
+    #[test]
+    fn parse_grouped_leading_delimiter() {
+        let err = parse_grouped(",123,456", '.', &[','], 3, 3, true).unwrap_err();
+        assert_eq!(err, UngroupError::MisplacedDelimiter);
+    }
+
+    #[test]
+    fn parse_grouped_without_validation() {
+        let n = parse_grouped("12,3456", '.', &[','], 3, 3, false).unwrap();
+        assert_eq!(n, "123456");
+    }
+
+    #[test]
+    fn format_rounded_half_away_from_zero() {
+        let x: f64 = 2.675;
+        let s = x.format_rounded(2, RoundingMode::HalfAwayFromZero, '.', ',', 3, 3, false);
+        assert_eq!(s, "2.68");
+    }
+
+    #[test]
+    fn format_rounded_half_to_even() {
+        let x: f64 = 0.125;
+        let s = x.format_rounded(2, RoundingMode::HalfToEven, '.', ',', 3, 3, false);
+        assert_eq!(s, "0.12");
+    }
+
+    #[test]
+    fn format_rounded_negative_half_away_from_zero() {
+        let x: f64 = -2.5;
+        let s = x.format_rounded(0, RoundingMode::HalfAwayFromZero, '.', ',', 3, 3, false);
+        assert_eq!(s, "-3");
+    }
+
+    #[test]
+    fn format_rounded_groups_after_carry() {
+        let x: f64 = 999999.995;
+        let s = x.format_rounded(2, RoundingMode::HalfAwayFromZero, '.', ',', 3, 3, false);
+        assert_eq!(s, "1,000,000.00");
+    }
+
+    #[test]
+    fn group_formatter_format_rounded() {
+        let fmt = GroupFormatter::commas();
+        let s = fmt.format_rounded(2.675_f64, 2, RoundingMode::HalfAwayFromZero);
+        assert_eq!(s, "2.68");
+    }
+
 }